@@ -1,5 +1,6 @@
 use pyo3::prelude::*;
 use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
 
 /// Board indices:
 /// - 0-6: Player 0's pits (left to right from their perspective)
@@ -12,6 +13,53 @@ pub const P0_STORE: usize = 14;
 pub const P1_STORE: usize = 15;
 pub const INITIAL_SEEDS: u8 = 7;
 
+/// Every seed on the board could in principle end up in a single pit, so
+/// size the per-pit key table to the full seed count (two starting sets of
+/// 7x7 seeds = 98), with one slot per count from 0 to 98 inclusive.
+const ZOBRIST_MAX_COUNT: usize = 99;
+
+/// Zobrist keys: `pit_keys[pit][count]` XORed in for each pit's current
+/// seed count, `burnt_keys[pit]` XORed in while a pit is burnt, plus one key
+/// toggled when it's player 1's turn.
+pub(crate) struct ZobristKeys {
+    pub(crate) pit_keys: [[u64; ZOBRIST_MAX_COUNT]; 16],
+    pub(crate) burnt_keys: [u64; 14],
+    pub(crate) player_key: u64,
+}
+
+pub(crate) fn zobrist_keys() -> &'static ZobristKeys {
+    static KEYS: OnceLock<ZobristKeys> = OnceLock::new();
+    KEYS.get_or_init(|| {
+        // Deterministic xorshift stream, same style as the rest of the crate's
+        // lightweight PRNG usage, so keys are stable across runs/processes.
+        let mut rng_state: u64 = 0x2545F4914F6CDD1D;
+        let mut next = || {
+            rng_state ^= rng_state << 13;
+            rng_state ^= rng_state >> 7;
+            rng_state ^= rng_state << 17;
+            rng_state
+        };
+
+        let mut pit_keys = [[0u64; ZOBRIST_MAX_COUNT]; 16];
+        for pit in pit_keys.iter_mut() {
+            for slot in pit.iter_mut() {
+                *slot = next();
+            }
+        }
+
+        let mut burnt_keys = [0u64; 14];
+        for key in burnt_keys.iter_mut() {
+            *key = next();
+        }
+
+        ZobristKeys {
+            pit_keys,
+            burnt_keys,
+            player_key: next(),
+        }
+    })
+}
+
 #[pyclass]
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub struct BoardState {
@@ -19,12 +67,20 @@ pub struct BoardState {
     pub pits: [u8; 16],
     #[pyo3(get)]
     pub current_player: u8,
+    /// Burnt ("mati") holes under the multi-round variant
+    /// (`RuleConfig::burnt_holes_enabled`): a burnt pit is skipped during
+    /// sowing and can never receive or capture seeds again. Only
+    /// `rounds::start_next_round` (or a caller of `from_pits`) sets these;
+    /// they stay all `false` otherwise.
+    #[pyo3(get)]
+    pub burnt: [bool; 14],
 }
 
 impl Hash for BoardState {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.pits.hash(state);
         self.current_player.hash(state);
+        self.burnt.hash(state);
     }
 }
 
@@ -43,14 +99,17 @@ impl BoardState {
         BoardState {
             pits,
             current_player: 0,
+            burnt: [false; 14],
         }
     }
 
     #[staticmethod]
-    pub fn from_pits(pits: [u8; 16], current_player: u8) -> Self {
+    #[pyo3(signature = (pits, current_player, burnt=[false; 14]))]
+    pub fn from_pits(pits: [u8; 16], current_player: u8, burnt: [bool; 14]) -> Self {
         BoardState {
             pits,
             current_player,
+            burnt,
         }
     }
 
@@ -96,6 +155,41 @@ impl BoardState {
         self.pits.iter().sum()
     }
 
+    /// Zobrist hash of this position, for use as a transposition-table key.
+    /// Computed from scratch by XORing each pit's `[pit][count]` key (plus
+    /// the side-to-move key), so it's the right path for states built
+    /// directly from arbitrary pits (e.g. `from_pits`). Code that already
+    /// holds a hash and is applying a move should prefer the incremental
+    /// `zobrist` on `MoveResult` instead of recomputing here.
+    pub fn zobrist(&self) -> u64 {
+        let keys = zobrist_keys();
+        let mut hash = 0u64;
+        for (pit, &count) in self.pits.iter().enumerate() {
+            hash ^= keys.pit_keys[pit][count as usize];
+        }
+        for (pit, &is_burnt) in self.burnt.iter().enumerate() {
+            if is_burnt {
+                hash ^= keys.burnt_keys[pit];
+            }
+        }
+        if self.current_player == 1 {
+            hash ^= keys.player_key;
+        }
+        hash
+    }
+
+    /// Encode this position as a fixed-shape `[f32]` feature vector for a
+    /// neural net: each pit's seed count (stores included, since they're
+    /// just pits 14/15) divided by the 98 seeds a game starts with, a burnt
+    /// indicator per pit, followed by a side-to-move indicator plane.
+    pub fn encode_planes(&self) -> Vec<f32> {
+        let total = ((P0_PITS.len() + P1_PITS.len()) as u32 * INITIAL_SEEDS as u32) as f32;
+        let mut planes: Vec<f32> = self.pits.iter().map(|&count| count as f32 / total).collect();
+        planes.extend(self.burnt.iter().map(|&is_burnt| if is_burnt { 1.0 } else { 0.0 }));
+        planes.push(self.current_player as f32);
+        planes
+    }
+
     fn __hash__(&self) -> u64 {
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
         self.hash(&mut hasher);
@@ -103,13 +197,15 @@ impl BoardState {
     }
 
     fn __eq__(&self, other: &Self) -> bool {
-        self.pits == other.pits && self.current_player == other.current_player
+        self.pits == other.pits
+            && self.current_player == other.current_player
+            && self.burnt == other.burnt
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "BoardState(pits={:?}, current_player={})",
-            self.pits, self.current_player
+            "BoardState(pits={:?}, current_player={}, burnt={:?})",
+            self.pits, self.current_player, self.burnt
         )
     }
 }
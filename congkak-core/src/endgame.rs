@@ -0,0 +1,163 @@
+use crate::board::BoardState;
+use crate::moves::{apply_move, get_final_scores, get_legal_moves, is_terminal};
+use crate::rules::RuleConfig;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+/// What a transposition-table entry's stored value actually bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TtEntry {
+    value: i32,
+    depth: u32,
+    bound: Bound,
+}
+
+/// Leaf/terminal evaluation: store difference from the current player's
+/// perspective. `get_final_scores` already folds in uncollected pit seeds,
+/// so this doubles as the exact terminal score once the game has ended.
+fn evaluate(state: &BoardState) -> i32 {
+    let (p0, p1) = get_final_scores(state);
+    if state.current_player == 0 {
+        p0 as i32 - p1 as i32
+    } else {
+        p1 as i32 - p0 as i32
+    }
+}
+
+/// Negamax with alpha-beta pruning, transposition-tabled on `hash` — the
+/// Zobrist key for `state`, threaded down from each move's `MoveResult.zobrist`
+/// so a node never calls `state.zobrist()` for its own key. A move
+/// with `extra_turn` keeps the same player to move, so its value is *not*
+/// negated — it's a continuation for the same side rather than a turn
+/// change, and is searched as a null-window-free recursive call at `depth - 1`.
+fn negamax(
+    state: &BoardState,
+    hash: u64,
+    rules: &RuleConfig,
+    depth: u32,
+    mut alpha: i32,
+    mut beta: i32,
+    tt: &mut HashMap<u64, TtEntry>,
+) -> i32 {
+    let key = hash;
+    if let Some(entry) = tt.get(&key) {
+        if entry.depth >= depth {
+            match entry.bound {
+                Bound::Exact => return entry.value,
+                Bound::Lower => alpha = alpha.max(entry.value),
+                Bound::Upper => beta = beta.min(entry.value),
+            }
+            if alpha >= beta {
+                return entry.value;
+            }
+        }
+    }
+
+    if depth == 0 || is_terminal(state) {
+        return evaluate(state);
+    }
+
+    let moves = get_legal_moves(state);
+    let original_alpha = alpha;
+    let mut best = i32::MIN;
+
+    for mv in moves {
+        let result = apply_move(state, mv, rules);
+        let value = if result.extra_turn {
+            negamax(&result.state, result.zobrist, rules, depth - 1, alpha, beta, tt)
+        } else {
+            -negamax(&result.state, result.zobrist, rules, depth - 1, -beta, -alpha, tt)
+        };
+
+        if value > best {
+            best = value;
+        }
+        if best > alpha {
+            alpha = best;
+        }
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    let bound = if best <= original_alpha {
+        Bound::Upper
+    } else if best >= beta {
+        Bound::Lower
+    } else {
+        Bound::Exact
+    };
+    tt.insert(key, TtEntry { value: best, depth, bound });
+
+    best
+}
+
+/// Exhaustively solve a (shallow enough) position with alpha-beta negamax.
+/// Returns `(best_move, value)` where `value` is the store difference from
+/// the current player's perspective under optimal play to `max_depth` ply.
+#[pyfunction]
+pub fn solve(state: &BoardState, rules: &RuleConfig, max_depth: u32) -> (usize, i32) {
+    let moves = get_legal_moves(state);
+    if moves.is_empty() {
+        return (0, evaluate(state));
+    }
+
+    let mut tt = HashMap::new();
+    let mut alpha = i32::MIN + 1;
+    let beta = i32::MAX - 1;
+    let mut best_move = moves[0];
+    let mut best_value = i32::MIN;
+
+    for mv in moves {
+        let result = apply_move(state, mv, rules);
+        let value = if result.extra_turn {
+            negamax(&result.state, result.zobrist, rules, max_depth.saturating_sub(1), alpha, beta, &mut tt)
+        } else {
+            -negamax(&result.state, result.zobrist, rules, max_depth.saturating_sub(1), -beta, -alpha, &mut tt)
+        };
+
+        if value > best_value {
+            best_value = value;
+            best_move = mv;
+        }
+        if value > alpha {
+            alpha = value;
+        }
+    }
+
+    (best_move, best_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Player 0 to move with exactly two legal pits: pit 1's single seed
+    /// lands in the (empty) pit 0 and captures pit 13's whole stash,
+    /// emptying player 1's side outright and ending the game then and
+    /// there, with player 0 banking every seed on the board (8-0); pit 4's
+    /// single seed just lands in the empty pit 3 with nothing opposite to
+    /// capture, leaving the game ongoing. `solve` should find the forced
+    /// win and report its exact value, since this position is shallow
+    /// enough to exhaust.
+    #[test]
+    fn test_solve_finds_the_forced_capture_win() {
+        let mut pits = [0u8; 16];
+        pits[1] = 1;
+        pits[4] = 1;
+        pits[13] = 6;
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
+        let rules = RuleConfig::default();
+
+        let (best_move, value) = solve(&state, &rules, 4);
+        assert_eq!(best_move, 1);
+        assert_eq!(value, 8);
+    }
+}
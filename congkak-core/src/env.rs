@@ -0,0 +1,298 @@
+use crate::board::{BoardState, P0_PITS, P1_PITS};
+use crate::moves::{apply_move, apply_simultaneous_moves, get_winner, is_terminal, IllegalMoveError};
+use crate::rules::{RuleConfig, StartMode};
+use pyo3::prelude::*;
+use std::ops::Range;
+
+/// Bonus folded into the terminal step's reward on top of the store-diff
+/// delta, so a win/loss carries more weight than a close-but-ongoing margin.
+const TERMINAL_BONUS: f64 = 10.0;
+
+/// Where a `CongkakEnv` is in resolving the current ply. `Sequential` covers
+/// both `StartMode::Sequential` play and every ply after a simultaneous
+/// opening has resolved, since once seats stop acting at the same time
+/// `BoardState::current_player` is all a caller needs.
+enum Phase {
+    Sequential,
+    /// Leader-follower opening: `leader` hasn't committed a move yet.
+    AwaitingLeader { leader: u8 },
+    /// Leader-follower opening: the leader committed `leader_move` and the
+    /// follower (whose `revealed_leader_move` now shows it) is pending.
+    AwaitingFollower { leader: u8, leader_move: usize },
+    /// Fully independent opening: neither seat sees the other's choice, so
+    /// whichever one calls `step` first is buffered here until the other commits.
+    AwaitingBoth { committed: Option<(u8, usize)> },
+}
+
+fn pit_range(player: u8) -> Range<usize> {
+    if player == 0 {
+        P0_PITS
+    } else {
+        P1_PITS
+    }
+}
+
+fn validate_player_pit(state: &BoardState, pit: usize, player: u8) -> Result<(), IllegalMoveError> {
+    if pit >= state.pits.len() {
+        return Err(IllegalMoveError::OutOfRange(pit));
+    }
+    if !pit_range(player).contains(&pit) {
+        return Err(IllegalMoveError::WrongOwner(pit, player));
+    }
+    if state.pits[pit] == 0 {
+        return Err(IllegalMoveError::EmptyPit(pit));
+    }
+    Ok(())
+}
+
+/// What an agent sees before choosing an action. `pending_players` has one
+/// entry in `Sequential` play and in a leader-follower opening (always the
+/// seat whose turn it is), but has *both* seats during the blind window of
+/// an independent opening, before either has committed. `action_mask`
+/// covers every pit either pending seat could legally play (player 0's and
+/// player 1's pits never overlap, so this stays unambiguous even with two
+/// pending seats). `revealed_leader_move` is only `Some` for the follower
+/// in a leader-follower opening — independent play never reveals anything.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct Observation {
+    #[pyo3(get)]
+    pub pits: [u8; 16],
+    #[pyo3(get)]
+    pub current_player: u8,
+    #[pyo3(get)]
+    pub burnt: [bool; 14],
+    #[pyo3(get)]
+    pub pending_players: Vec<u8>,
+    #[pyo3(get)]
+    pub revealed_leader_move: Option<usize>,
+    #[pyo3(get)]
+    pub action_mask: [bool; 16],
+}
+
+/// Result of one `CongkakEnv::step` call.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct StepResult {
+    #[pyo3(get)]
+    pub observation: Observation,
+    /// Store-diff delta this step produced for the acting seat (their store
+    /// minus the opponent's, before vs. after), plus `TERMINAL_BONUS` if
+    /// this step ended the game in their favor (`-TERMINAL_BONUS` if it
+    /// ended in the opponent's). Zero for a commit that only buffers a move
+    /// during a simultaneous opening, since nothing has been applied yet.
+    #[pyo3(get)]
+    pub reward: f64,
+    #[pyo3(get)]
+    pub done: bool,
+}
+
+/// A Gym-style `reset`/`step` wrapper over the engine, so a training loop
+/// can drive a game one action at a time instead of calling `apply_move`
+/// directly. Handles the extra-turn rule (the same seat keeps acting, so
+/// `pending_players` doesn't flip) and `RuleConfig::start_mode`'s
+/// simultaneous openings, including buffering both seats' blind commits
+/// under `SimultaneousIndependent` so a multi-agent loop can drive either
+/// seat in either order.
+#[pyclass]
+pub struct CongkakEnv {
+    rules: RuleConfig,
+    state: BoardState,
+    phase: Phase,
+}
+
+#[pymethods]
+impl CongkakEnv {
+    #[new]
+    pub fn new(rules: RuleConfig) -> Self {
+        let mut env = CongkakEnv {
+            rules,
+            state: BoardState::initial(),
+            phase: Phase::Sequential,
+        };
+        env.phase = env.initial_phase();
+        env
+    }
+
+    /// Start a fresh game and return the opening observation.
+    pub fn reset(&mut self) -> Observation {
+        self.state = BoardState::initial();
+        self.phase = self.initial_phase();
+        self.observation()
+    }
+
+    /// The current board state, e.g. for inspecting a finished episode's
+    /// final stores without re-deriving them from `Observation`.
+    pub fn state(&self) -> BoardState {
+        self.state
+    }
+
+    /// Submit `action` (a pit index) on behalf of `seat`. `seat` must be
+    /// one of the currently pending players (see `Observation::pending_players`);
+    /// anything else raises a `ValueError`, same as an illegal pit.
+    pub fn step(&mut self, seat: u8, action: usize) -> PyResult<StepResult> {
+        if !self.pending_players().contains(&seat) {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "seat {seat} has no move pending this ply"
+            )));
+        }
+        validate_player_pit(&self.state, action, seat)?;
+
+        match self.phase {
+            Phase::Sequential => {
+                let before = self.state;
+                let result = apply_move(&self.state, action, &self.rules);
+                self.state = result.state;
+                if !result.extra_turn {
+                    self.phase = Phase::Sequential;
+                }
+                let reward = self.reward_for(seat, &before);
+                let done = is_terminal(&self.state);
+                Ok(StepResult { observation: self.observation(), reward, done })
+            }
+            Phase::AwaitingLeader { leader } => {
+                self.phase = Phase::AwaitingFollower { leader, leader_move: action };
+                Ok(StepResult { observation: self.observation(), reward: 0.0, done: false })
+            }
+            Phase::AwaitingFollower { leader, leader_move } => {
+                let (p0_pit, p1_pit) = if leader == 0 { (leader_move, action) } else { (action, leader_move) };
+                self.resolve_simultaneous(seat, p0_pit, p1_pit)
+            }
+            Phase::AwaitingBoth { committed: None } => {
+                self.phase = Phase::AwaitingBoth { committed: Some((seat, action)) };
+                Ok(StepResult { observation: self.observation(), reward: 0.0, done: false })
+            }
+            Phase::AwaitingBoth { committed: Some((first_seat, first_move)) } => {
+                let (p0_pit, p1_pit) =
+                    if first_seat == 0 { (first_move, action) } else { (action, first_move) };
+                self.resolve_simultaneous(seat, p0_pit, p1_pit)
+            }
+        }
+    }
+}
+
+impl CongkakEnv {
+    fn initial_phase(&self) -> Phase {
+        match self.rules.start_mode {
+            StartMode::Sequential => Phase::Sequential,
+            StartMode::SimultaneousIndependent => Phase::AwaitingBoth { committed: None },
+            StartMode::SimultaneousLeaderFollower => {
+                Phase::AwaitingLeader { leader: self.rules.select_leader() }
+            }
+        }
+    }
+
+    fn pending_players(&self) -> Vec<u8> {
+        match &self.phase {
+            Phase::Sequential => vec![self.state.current_player],
+            Phase::AwaitingLeader { leader } => vec![*leader],
+            Phase::AwaitingFollower { leader, .. } => vec![1 - leader],
+            Phase::AwaitingBoth { committed: None } => vec![0, 1],
+            Phase::AwaitingBoth { committed: Some((seat, _)) } => vec![1 - seat],
+        }
+    }
+
+    /// Apply both halves of a simultaneous opening, fold the result into
+    /// `self.state`, drop back to `Phase::Sequential` (the opening only
+    /// ever covers the first ply), and score the reward from `seat`'s
+    /// perspective — whichever of the two seats happened to make this
+    /// particular `step` call the resolving one.
+    fn resolve_simultaneous(&mut self, seat: u8, p0_pit: usize, p1_pit: usize) -> PyResult<StepResult> {
+        let before = self.state;
+        let result = apply_simultaneous_moves(&self.state, p0_pit, p1_pit, &self.rules);
+        self.state = result.state;
+        self.phase = Phase::Sequential;
+
+        let reward = self.reward_for(seat, &before);
+        let done = is_terminal(&self.state);
+        Ok(StepResult { observation: self.observation(), reward, done })
+    }
+
+    /// Store-diff delta for `seat` from `before` to `self.state`, plus a
+    /// terminal win/loss bonus if this step ended the game.
+    fn reward_for(&self, seat: u8, before: &BoardState) -> f64 {
+        let diff_before = before.get_store(seat) as i32 - before.get_store(1 - seat) as i32;
+        let diff_after = self.state.get_store(seat) as i32 - self.state.get_store(1 - seat) as i32;
+        let mut reward = (diff_after - diff_before) as f64;
+
+        if is_terminal(&self.state) {
+            match get_winner(&self.state) {
+                -1 => {}
+                winner if winner as u8 == seat => reward += TERMINAL_BONUS,
+                _ => reward -= TERMINAL_BONUS,
+            }
+        }
+
+        reward
+    }
+
+    fn observation(&self) -> Observation {
+        let pending_players = self.pending_players();
+        let mut action_mask = [false; 16];
+        for &player in &pending_players {
+            for pit in pit_range(player) {
+                if self.state.pits[pit] > 0 {
+                    action_mask[pit] = true;
+                }
+            }
+        }
+
+        let revealed_leader_move = match self.phase {
+            Phase::AwaitingFollower { leader_move, .. } => Some(leader_move),
+            _ => None,
+        };
+
+        Observation {
+            pits: self.state.pits,
+            current_player: self.state.current_player,
+            burnt: self.state.burnt,
+            pending_players,
+            revealed_leader_move,
+            action_mask,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::LeaderSelection;
+
+    #[test]
+    fn test_extra_turn_keeps_the_same_seat_pending() {
+        let mut env = CongkakEnv::new(RuleConfig::default());
+        let obs = env.reset();
+        assert_eq!(obs.pending_players, vec![0]);
+
+        // On a fresh board, pit 6 holds exactly `INITIAL_SEEDS` seeds, which
+        // sow straight into player 0's own store (extra turn): the seat
+        // pending the next action must stay 0, not flip to 1.
+        let step = env.step(0, 6).unwrap();
+        assert!(!step.done);
+        assert_eq!(step.observation.current_player, 0);
+        assert_eq!(step.observation.pending_players, vec![0]);
+    }
+
+    #[test]
+    fn test_leader_follower_opening_reveals_then_resolves() {
+        let rules = RuleConfig {
+            start_mode: StartMode::SimultaneousLeaderFollower,
+            leader_selection: LeaderSelection::AlwaysP0,
+            ..RuleConfig::default()
+        };
+        let mut env = CongkakEnv::new(rules);
+        let obs = env.reset();
+        assert_eq!(obs.pending_players, vec![0]);
+        assert_eq!(obs.revealed_leader_move, None);
+
+        let leader_step = env.step(0, 0).unwrap();
+        assert!(!leader_step.done);
+        assert_eq!(leader_step.reward, 0.0, "nothing is applied to the board until the follower commits");
+        assert_eq!(leader_step.observation.pending_players, vec![1]);
+        assert_eq!(leader_step.observation.revealed_leader_move, Some(0));
+
+        let follower_step = env.step(1, 9).unwrap();
+        assert_eq!(follower_step.observation.revealed_leader_move, None, "the opening is over once both seats have moved");
+        assert_eq!(follower_step.observation.pending_players, vec![follower_step.observation.current_player]);
+    }
+}
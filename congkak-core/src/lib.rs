@@ -1,7 +1,14 @@
 pub mod board;
+pub mod endgame;
+pub mod env;
+pub mod match_series;
 pub mod moves;
+pub mod nash;
+pub mod rounds;
 pub mod rules;
+pub mod search;
 pub mod simulation;
+pub mod solver;
 
 use pyo3::prelude::*;
 
@@ -9,8 +16,6 @@ use pyo3::prelude::*;
 fn congkak_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // board types
     m.add_class::<board::BoardState>()?;
-    m.add_class::<board::SimultaneousPhase>()?;
-    m.add_class::<board::SimultaneousMoveState>()?;
 
     // rules
     m.add_class::<rules::RuleConfig>()?;
@@ -21,7 +26,9 @@ fn congkak_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<moves::MoveResult>()?;
     m.add_class::<moves::SimultaneousMoveResult>()?;
     m.add_function(wrap_pyfunction!(moves::apply_move, m)?)?;
+    m.add_function(wrap_pyfunction!(moves::try_apply_move, m)?)?;
     m.add_function(wrap_pyfunction!(moves::apply_simultaneous_moves, m)?)?;
+    m.add_function(wrap_pyfunction!(moves::try_apply_simultaneous_moves, m)?)?;
     m.add_function(wrap_pyfunction!(moves::get_legal_moves, m)?)?;
     m.add_function(wrap_pyfunction!(moves::is_terminal, m)?)?;
     m.add_function(wrap_pyfunction!(moves::get_winner, m)?)?;
@@ -31,6 +38,40 @@ fn congkak_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(simulation::random_playout, m)?)?;
     m.add_function(wrap_pyfunction!(simulation::batch_random_playouts, m)?)?;
     m.add_function(wrap_pyfunction!(simulation::perft, m)?)?;
+    m.add_function(wrap_pyfunction!(simulation::generate_seeds, m)?)?;
+
+    // search
+    m.add_class::<search::MctsResult>()?;
+    m.add_class::<search::SelfPlayStep>()?;
+    m.add_function(wrap_pyfunction!(search::mcts_best_move, m)?)?;
+    m.add_function(wrap_pyfunction!(search::mcts_search, m)?)?;
+    m.add_function(wrap_pyfunction!(search::puct_search, m)?)?;
+    m.add_function(wrap_pyfunction!(search::self_play_game, m)?)?;
+
+    // endgame solver
+    m.add_function(wrap_pyfunction!(endgame::solve, m)?)?;
+
+    // depth-limited alpha-beta solver
+    m.add_class::<solver::Solver>()?;
+    m.add_function(wrap_pyfunction!(solver::best_move, m)?)?;
+
+    // multi-round ("babak") play with burnt holes
+    m.add_class::<rounds::RoundResult>()?;
+    m.add_function(wrap_pyfunction!(rounds::start_next_round, m)?)?;
+
+    // mixed-strategy solver for simultaneous-move play
+    m.add_class::<nash::NashResult>()?;
+    m.add_function(wrap_pyfunction!(nash::solve_simultaneous, m)?)?;
+
+    // Gym-style RL environment
+    m.add_class::<env::CongkakEnv>()?;
+    m.add_class::<env::Observation>()?;
+    m.add_class::<env::StepResult>()?;
+
+    // head-to-head match series between two agents
+    m.add_class::<match_series::GameResult>()?;
+    m.add_class::<match_series::MatchResult>()?;
+    m.add_function(wrap_pyfunction!(match_series::play_match, m)?)?;
 
     Ok(())
 }
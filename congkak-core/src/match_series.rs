@@ -0,0 +1,182 @@
+use crate::env::{CongkakEnv, Observation};
+use crate::moves::{get_final_scores, get_winner};
+use crate::rules::RuleConfig;
+use pyo3::prelude::*;
+
+/// Call a Python `(observation) -> action: int` agent for whichever seat
+/// it's currently controlling.
+fn call_agent(py: Python<'_>, agent: &Py<PyAny>, observation: &Observation) -> PyResult<usize> {
+    let obs_obj = Py::new(py, observation.clone())?;
+    agent.bind(py).call1((obs_obj,))?.extract()
+}
+
+/// Outcome of one game within a `Match`.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct GameResult {
+    /// Index (0 or 1) of the agent that moved first this game.
+    #[pyo3(get)]
+    pub starting_agent: u8,
+    /// Final score (store plus any seeds left in that seat's pits, as
+    /// `get_final_scores` counts it) for agent 0 and agent 1, already remapped from
+    /// whichever board seat each agent occupied this particular game.
+    #[pyo3(get)]
+    pub scores: (u8, u8),
+    /// Winning agent index (0 or 1), or -1 for a draw.
+    #[pyo3(get)]
+    pub winner: i8,
+}
+
+/// Play one game to completion, with `agents[0]`/`agents[1]` each driven by
+/// calling them as `(observation) -> action` for whichever board seat they
+/// currently occupy. `starting_agent` picks which agent sits in board seat
+/// 0 this game, since the board itself always deals seat 0 in first.
+fn play_game(
+    py: Python<'_>,
+    rules: &RuleConfig,
+    agents: [&Py<PyAny>; 2],
+    starting_agent: u8,
+) -> PyResult<GameResult> {
+    let seat_to_agent = if starting_agent == 0 { [0u8, 1u8] } else { [1u8, 0u8] };
+
+    let mut env = CongkakEnv::new(*rules);
+    let mut observation = env.reset();
+    let mut done = false;
+
+    while !done {
+        for seat in observation.pending_players.clone() {
+            let agent = agents[seat_to_agent[seat as usize] as usize];
+            let action = call_agent(py, agent, &observation)?;
+            let step_result = env.step(seat, action)?;
+            observation = step_result.observation;
+            done = step_result.done;
+            if done {
+                break;
+            }
+        }
+    }
+
+    let final_state = env.state();
+    let (p0_score, p1_score) = get_final_scores(&final_state);
+    let scores = if seat_to_agent[0] == 0 { (p0_score, p1_score) } else { (p1_score, p0_score) };
+    let winner = match get_winner(&final_state) {
+        -1 => -1,
+        seat => seat_to_agent[seat as usize] as i8,
+    };
+
+    Ok(GameResult { starting_agent, scores, winner })
+}
+
+/// Result of a best-of-`num_games` series between two agents.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct MatchResult {
+    /// Every game played, in order.
+    #[pyo3(get)]
+    pub games: Vec<GameResult>,
+    /// Games won by agent 0 and agent 1.
+    #[pyo3(get)]
+    pub wins: (u32, u32),
+    /// Aggregate seed count banked by agent 0 and agent 1 across the match.
+    #[pyo3(get)]
+    pub total_scores: (u64, u64),
+    /// Match winner: whichever agent reached `ceil(num_games / 2)` wins
+    /// first, else whoever banked more seeds overall, or -1 on a dead-even
+    /// tie on both counts.
+    #[pyo3(get)]
+    pub winner: i8,
+}
+
+/// Play a best-of-`num_games` series between `agent_a` and `agent_b` under
+/// `rules`, alternating which agent moves first each game so neither side
+/// is consistently favored by `StartMode`/first-move advantage. Stops early
+/// once either agent clinches `ceil(num_games / 2)` wins; otherwise the
+/// match winner is whoever banked more seeds in aggregate.
+#[pyfunction]
+pub fn play_match(
+    py: Python<'_>,
+    rules: &RuleConfig,
+    agent_a: Py<PyAny>,
+    agent_b: Py<PyAny>,
+    num_games: u32,
+) -> PyResult<MatchResult> {
+    let agents = [&agent_a, &agent_b];
+    let win_threshold = num_games.div_ceil(2);
+
+    let mut games = Vec::with_capacity(num_games as usize);
+    let mut wins = (0u32, 0u32);
+    let mut total_scores = (0u64, 0u64);
+
+    for game_index in 0..num_games {
+        let starting_agent = (game_index % 2) as u8;
+        let game = play_game(py, rules, agents, starting_agent)?;
+
+        match game.winner {
+            0 => wins.0 += 1,
+            1 => wins.1 += 1,
+            _ => {}
+        }
+        total_scores.0 += game.scores.0 as u64;
+        total_scores.1 += game.scores.1 as u64;
+        games.push(game);
+
+        if wins.0 >= win_threshold || wins.1 >= win_threshold {
+            break;
+        }
+    }
+
+    let winner = match_winner(wins, total_scores, win_threshold, games.len());
+
+    Ok(MatchResult { games, wins, total_scores, winner })
+}
+
+/// Decide a match winner from the tallies `play_match` accumulated:
+/// whoever clinched `win_threshold` wins, else whoever banked more seeds in
+/// aggregate, else a draw (-1) — and, with no games played at all (e.g.
+/// `num_games == 0`), a draw regardless of `win_threshold` (which is 0 and
+/// would otherwise vacuously satisfy `wins.0 >= win_threshold`).
+fn match_winner(wins: (u32, u32), total_scores: (u64, u64), win_threshold: u32, games_played: usize) -> i8 {
+    if games_played == 0 {
+        -1
+    } else if wins.0 >= win_threshold {
+        0
+    } else if wins.1 >= win_threshold {
+        1
+    } else if total_scores.0 > total_scores.1 {
+        0
+    } else if total_scores.1 > total_scores.0 {
+        1
+    } else {
+        -1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_winner_is_a_draw_with_no_games_played() {
+        // num_games == 0: win_threshold is also 0, so `wins.0 >= win_threshold`
+        // would otherwise be vacuously true and wrongly declare agent 0 the winner.
+        assert_eq!(match_winner((0, 0), (0, 0), 0, 0), -1);
+    }
+
+    #[test]
+    fn test_match_winner_stops_at_the_win_threshold() {
+        // Best-of-5: first to 3 wins, even if not every game was played.
+        assert_eq!(match_winner((3, 1), (10, 10), 3, 4), 0);
+        assert_eq!(match_winner((1, 3), (10, 10), 3, 4), 1);
+    }
+
+    #[test]
+    fn test_match_winner_falls_back_to_aggregate_score() {
+        assert_eq!(match_winner((2, 2), (15, 10), 3, 4), 0);
+        assert_eq!(match_winner((2, 2), (10, 15), 3, 4), 1);
+    }
+
+    #[test]
+    fn test_match_winner_is_a_draw_on_a_dead_even_tie() {
+        assert_eq!(match_winner((2, 2), (10, 10), 3, 4), -1);
+    }
+}
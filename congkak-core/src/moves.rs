@@ -1,6 +1,51 @@
-use crate::board::{BoardState, P0_PITS, P0_STORE, P1_PITS, P1_STORE};
+use crate::board::{zobrist_keys, BoardState, P0_PITS, P0_STORE, P1_PITS, P1_STORE};
 use crate::rules::RuleConfig;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use std::fmt;
+
+/// Why a requested move can't be played. Used by the `try_*` APIs to raise a
+/// `ValueError` in Python instead of panicking.
+#[derive(Clone, Copy, Debug)]
+pub enum IllegalMoveError {
+    OutOfRange(usize),
+    WrongOwner(usize, u8),
+    EmptyPit(usize),
+}
+
+impl fmt::Display for IllegalMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IllegalMoveError::OutOfRange(pit) => write!(f, "pit out of range: {pit}"),
+            IllegalMoveError::WrongOwner(pit, player) => {
+                write!(f, "pit does not belong to player {player}: {pit}")
+            }
+            IllegalMoveError::EmptyPit(pit) => write!(f, "pit is empty: {pit}"),
+        }
+    }
+}
+
+impl std::error::Error for IllegalMoveError {}
+
+impl From<IllegalMoveError> for PyErr {
+    fn from(err: IllegalMoveError) -> PyErr {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Check that `pit` is a legal pit for `player` to sow from in `state`.
+fn validate_pit(state: &BoardState, pit: usize, player: u8) -> Result<(), IllegalMoveError> {
+    if pit >= state.pits.len() {
+        return Err(IllegalMoveError::OutOfRange(pit));
+    }
+    if !is_player_pit(pit, player) {
+        return Err(IllegalMoveError::WrongOwner(pit, player));
+    }
+    if state.pits[pit] == 0 {
+        return Err(IllegalMoveError::EmptyPit(pit));
+    }
+    Ok(())
+}
 
 /// Result of applying a move
 #[pyclass]
@@ -12,6 +57,11 @@ pub struct MoveResult {
     pub extra_turn: bool,
     #[pyo3(get)]
     pub captured: u8,
+    /// Zobrist hash of `state`: the move hashes the parent position once,
+    /// then updates that hash pit by pit while sowing, so search/TT code can
+    /// key on it without hashing the resulting board again.
+    #[pyo3(get)]
+    pub zobrist: u64,
 }
 
 /// Get the opposite pit index for capture
@@ -65,6 +115,15 @@ fn next_position(pos: usize) -> usize {
     }
 }
 
+/// XOR a single pit's count key out and back in after it changes, so callers
+/// can maintain a running Zobrist hash without rescanning the whole board.
+fn rehash_pit(hash: &mut u64, keys: &crate::board::ZobristKeys, pit: usize, old: u8, new: u8) {
+    *hash ^= keys.pit_keys[pit][old as usize];
+    *hash ^= keys.pit_keys[pit][new as usize];
+}
+
+/// Sow from `pit`. Callers must validate the move first (see `validate_pit`);
+/// this only re-asserts the invariant in debug builds.
 fn apply_move_internal(
     state: &BoardState,
     pit: usize,
@@ -75,12 +134,16 @@ fn apply_move_internal(
     let my_store = player_store(player);
     let opp_store = opponent_store(player);
 
-    assert!(pit < pits.len(), "pit out of range: {pit}");
-    assert!(is_player_pit(pit, player), "pit does not belong to player: {pit}");
-    assert!(pits[pit] > 0, "pit is empty: {pit}");
+    debug_assert!(pit < pits.len(), "pit out of range: {pit}");
+    debug_assert!(is_player_pit(pit, player), "pit does not belong to player: {pit}");
+    debug_assert!(pits[pit] > 0, "pit is empty: {pit}");
+
+    let keys = zobrist_keys();
+    let mut hash = state.zobrist();
 
     // pick up seeds from selected pit
     let mut seeds = pits[pit];
+    rehash_pit(&mut hash, keys, pit, pits[pit], 0);
     pits[pit] = 0;
 
     let mut current_pos = pit;
@@ -99,12 +162,18 @@ fn apply_move_internal(
             continue;
         }
 
+        // skip burnt ("mati") holes: they never receive seeds again
+        if current_pos < 14 && state.burnt[current_pos] {
+            continue;
+        }
+
         // track if we pass through our store (for capture_requires_loop rule)
         if current_pos == my_store {
             has_looped = true;
         }
 
         // drop one seed
+        rehash_pit(&mut hash, keys, current_pos, pits[current_pos], pits[current_pos] + 1);
         pits[current_pos] += 1;
         seeds -= 1;
         steps += 1;
@@ -125,6 +194,7 @@ fn apply_move_internal(
                 // relay sowing: if pit now has more than 1 seed, pick up and continue
                 if landed_count > 1 {
                     seeds = pits[current_pos];
+                    rehash_pit(&mut hash, keys, current_pos, pits[current_pos], 0);
                     pits[current_pos] = 0;
                     continue;
                 }
@@ -138,13 +208,18 @@ fn apply_move_internal(
                     let opp_seeds = pits[opp_pit];
                     if opp_seeds > 0 {
                         captured = opp_seeds + 1;
+                        rehash_pit(&mut hash, keys, my_store, pits[my_store], pits[my_store] + opp_seeds + 1);
                         pits[my_store] += opp_seeds + 1;
+                        rehash_pit(&mut hash, keys, current_pos, pits[current_pos], 0);
                         pits[current_pos] = 0;
+                        rehash_pit(&mut hash, keys, opp_pit, pits[opp_pit], 0);
                         pits[opp_pit] = 0;
                     }
                 } else if !is_my_pit && rules.forfeit_enabled {
                     // forfeit: seed goes to opponent's store
+                    rehash_pit(&mut hash, keys, opp_store, pits[opp_store], pits[opp_store] + 1);
                     pits[opp_store] += 1;
+                    rehash_pit(&mut hash, keys, current_pos, pits[current_pos], 0);
                     pits[current_pos] = 0;
                 }
             }
@@ -158,13 +233,19 @@ fn apply_move_internal(
         1 - player
     };
 
+    if next_player != player {
+        hash ^= keys.player_key;
+    }
+
     let result = MoveResult {
         state: BoardState {
             pits,
             current_player: next_player,
+            burnt: state.burnt,
         },
         extra_turn,
         captured,
+        zobrist: hash,
     };
 
     (result, steps)
@@ -172,12 +253,29 @@ fn apply_move_internal(
 
 /// Apply a move and return the resulting state
 /// This implements relay sowing with all rule variants
+///
+/// Panics on an illegal move (out-of-range pit, wrong owner, empty pit); use
+/// `try_apply_move` if the pit comes from untrusted input and a `ValueError`
+/// is preferred over a process abort.
 #[pyfunction]
 pub fn apply_move(state: &BoardState, pit: usize, rules: &RuleConfig) -> MoveResult {
+    validate_pit(state, pit, state.current_player)
+        .unwrap_or_else(|e| panic!("{e}"));
     let (result, _) = apply_move_internal(state, pit, rules);
     result
 }
 
+/// Like `apply_move`, but returns a `ValueError` instead of panicking when
+/// `pit` is out of range, belongs to the other player, or is empty. Safe to
+/// call with candidate moves from search/solver code or from Python without
+/// risking an abort on a malformed `BoardState`.
+#[pyfunction]
+pub fn try_apply_move(state: &BoardState, pit: usize, rules: &RuleConfig) -> PyResult<MoveResult> {
+    validate_pit(state, pit, state.current_player)?;
+    let (result, _) = apply_move_internal(state, pit, rules);
+    Ok(result)
+}
+
 /// Get all legal moves for the current player
 #[pyfunction]
 pub fn get_legal_moves(state: &BoardState) -> Vec<usize> {
@@ -244,12 +342,15 @@ struct SimPlayerState {
     captured: u8,
     my_store: usize,
     opp_store: usize,
+    burnt: [bool; 14],
 }
 
 impl SimPlayerState {
-    fn new(player: u8, start_pit: usize, base_pits: &[u8; 16]) -> Self {
+    /// Caller must validate `start_pit` first (see `validate_pit`); this only
+    /// re-asserts the invariant in debug builds.
+    fn new(player: u8, start_pit: usize, base_pits: &[u8; 16], burnt: [bool; 14]) -> Self {
         let seeds = base_pits[start_pit];
-        assert!(seeds > 0, "start pit is empty: {start_pit}");
+        debug_assert!(seeds > 0, "start pit is empty: {start_pit}");
         let mut delta = [0i16; 16];
         delta[start_pit] = -(seeds as i16);
         Self {
@@ -264,6 +365,7 @@ impl SimPlayerState {
             captured: 0,
             my_store: player_store(player),
             opp_store: opponent_store(player),
+            burnt,
         }
     }
 
@@ -273,7 +375,9 @@ impl SimPlayerState {
         }
 
         self.current_pos = next_position(self.current_pos);
-        while self.current_pos == self.opp_store {
+        while self.current_pos == self.opp_store
+            || (self.current_pos < 14 && self.burnt[self.current_pos])
+        {
             self.current_pos = next_position(self.current_pos);
         }
 
@@ -362,6 +466,10 @@ pub struct SimultaneousMoveResult {
 
 /// Apply simultaneous moves from both players.
 /// Both players pick up seeds and sow. Captures and extra turns are resolved together.
+///
+/// Panics on an illegal move for either seat; use `try_apply_simultaneous_moves`
+/// if the pits come from untrusted input and a `ValueError` is preferred over
+/// a process abort.
 #[pyfunction]
 pub fn apply_simultaneous_moves(
     state: &BoardState,
@@ -369,15 +477,35 @@ pub fn apply_simultaneous_moves(
     p1_pit: usize,
     rules: &RuleConfig,
 ) -> SimultaneousMoveResult {
-    // validate moves
-    assert!(P0_PITS.contains(&p0_pit), "p0_pit out of range: {p0_pit}");
-    assert!(P1_PITS.contains(&p1_pit), "p1_pit out of range: {p1_pit}");
-    assert!(state.pits[p0_pit] > 0, "p0 pit is empty: {p0_pit}");
-    assert!(state.pits[p1_pit] > 0, "p1 pit is empty: {p1_pit}");
+    validate_pit(state, p0_pit, 0).unwrap_or_else(|e| panic!("{e}"));
+    validate_pit(state, p1_pit, 1).unwrap_or_else(|e| panic!("{e}"));
+    run_simultaneous_moves(state, p0_pit, p1_pit, rules)
+}
 
+/// Like `apply_simultaneous_moves`, but returns a `ValueError` instead of
+/// panicking when either pit is out of range, belongs to the other player,
+/// or is empty.
+#[pyfunction]
+pub fn try_apply_simultaneous_moves(
+    state: &BoardState,
+    p0_pit: usize,
+    p1_pit: usize,
+    rules: &RuleConfig,
+) -> PyResult<SimultaneousMoveResult> {
+    validate_pit(state, p0_pit, 0)?;
+    validate_pit(state, p1_pit, 1)?;
+    Ok(run_simultaneous_moves(state, p0_pit, p1_pit, rules))
+}
+
+fn run_simultaneous_moves(
+    state: &BoardState,
+    p0_pit: usize,
+    p1_pit: usize,
+    rules: &RuleConfig,
+) -> SimultaneousMoveResult {
     let base_pits = state.pits;
-    let mut p0_state = SimPlayerState::new(0, p0_pit, &base_pits);
-    let mut p1_state = SimPlayerState::new(1, p1_pit, &base_pits);
+    let mut p0_state = SimPlayerState::new(0, p0_pit, &base_pits, state.burnt);
+    let mut p1_state = SimPlayerState::new(1, p1_pit, &base_pits, state.burnt);
 
     // run sowing in lock-step so relay/capture/forfeit sees the combined board.
     while !(p0_state.done && p1_state.done) {
@@ -429,6 +557,7 @@ pub fn apply_simultaneous_moves(
         state: BoardState {
             pits: final_pits,
             current_player: next_player,
+            burnt: state.burnt,
         },
         p0_extra_turn: p0_state.extra_turn,
         p1_extra_turn: p1_state.extra_turn,
@@ -472,7 +601,7 @@ mod tests {
         // clockwise sowing: 0 -> P0_STORE (14), so 1 seed from pit 0 lands in store
         let mut pits = [0u8; 16];
         pits[0] = 1;
-        let state = BoardState::from_pits(pits, 0);
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
         let rules = RuleConfig::default();
         let result = apply_move(&state, 0, &rules);
         assert!(result.extra_turn);
@@ -480,12 +609,21 @@ mod tests {
         assert_eq!(result.state.pits[P0_STORE], 1);
     }
 
+    #[test]
+    fn test_move_result_zobrist_matches_recomputed_hash() {
+        let state = BoardState::initial();
+        let rules = RuleConfig::default();
+        let result = apply_move(&state, 0, &rules);
+
+        assert_eq!(result.zobrist, result.state.zobrist());
+    }
+
     #[test]
     fn test_simultaneous_preserves_selected_pit_deposit() {
         let mut pits = [0u8; 16];
         pits[0] = 7;
         pits[13] = 7;
-        let state = BoardState::from_pits(pits, 0);
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
         let rules = RuleConfig::default();
         let result = apply_simultaneous_moves(&state, 0, 13, &rules);
 
@@ -497,7 +635,7 @@ mod tests {
         let mut pits = [0u8; 16];
         pits[0] = 8;
         pits[7] = 1;
-        let state = BoardState::from_pits(pits, 0);
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
         let rules = RuleConfig::default();
         let result = apply_simultaneous_moves(&state, 0, 7, &rules);
 
@@ -511,11 +649,75 @@ mod tests {
         pits[4] = 1;
         pits[7] = 1;
         pits[10] = 5;
-        let state = BoardState::from_pits(pits, 0);
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
         let rules = RuleConfig::default();
         let result = apply_simultaneous_moves(&state, 4, 7, &rules);
 
         assert_eq!(result.state.pits[10], 0);
         assert_eq!(result.state.pits[P0_STORE], 6);
     }
+
+    #[test]
+    fn test_try_apply_move_accepts_a_legal_move() {
+        let state = BoardState::initial();
+        let rules = RuleConfig::default();
+        assert!(try_apply_move(&state, 0, &rules).is_ok());
+    }
+
+    #[test]
+    fn test_try_apply_move_rejects_out_of_range_pit() {
+        let state = BoardState::initial();
+        let rules = RuleConfig::default();
+        assert!(try_apply_move(&state, 16, &rules).is_err());
+    }
+
+    #[test]
+    fn test_try_apply_move_rejects_wrong_owner_pit() {
+        let state = BoardState::initial();
+        let rules = RuleConfig::default();
+        // pit 7 belongs to player 1, but it's player 0's turn on a fresh board.
+        assert!(try_apply_move(&state, 7, &rules).is_err());
+    }
+
+    #[test]
+    fn test_try_apply_move_rejects_empty_pit() {
+        let mut pits = [0u8; 16];
+        pits[1] = 3;
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
+        let rules = RuleConfig::default();
+        assert!(try_apply_move(&state, 0, &rules).is_err());
+    }
+
+    #[test]
+    fn test_try_apply_simultaneous_moves_accepts_legal_moves() {
+        let state = BoardState::initial();
+        let rules = RuleConfig::default();
+        assert!(try_apply_simultaneous_moves(&state, 0, 7, &rules).is_ok());
+    }
+
+    #[test]
+    fn test_try_apply_simultaneous_moves_rejects_wrong_owner_pit() {
+        let state = BoardState::initial();
+        let rules = RuleConfig::default();
+        // pit 0 belongs to player 0, not player 1.
+        assert!(try_apply_simultaneous_moves(&state, 0, 0, &rules).is_err());
+    }
+
+    #[test]
+    fn test_try_apply_simultaneous_moves_rejects_out_of_range_pit() {
+        let state = BoardState::initial();
+        let rules = RuleConfig::default();
+        assert!(try_apply_simultaneous_moves(&state, 16, 7, &rules).is_err());
+    }
+
+    #[test]
+    fn test_try_apply_simultaneous_moves_rejects_empty_pit() {
+        let mut pits = [0u8; 16];
+        pits[0] = 3;
+        pits[8] = 3;
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
+        let rules = RuleConfig::default();
+        // pit 7 is empty.
+        assert!(try_apply_simultaneous_moves(&state, 0, 7, &rules).is_err());
+    }
 }
@@ -0,0 +1,172 @@
+use crate::board::{BoardState, P0_PITS, P1_PITS};
+use crate::moves::apply_simultaneous_moves;
+use crate::rules::RuleConfig;
+use crate::solver::Solver;
+use pyo3::prelude::*;
+
+/// Number of fictitious-play rounds used to approximate the matrix game's
+/// equilibrium. Dependency-free stand-in for an LP solver: a few thousand
+/// rounds is enough for the empirical strategy to settle down on boards
+/// this small (at most 7 moves per side).
+const FICTITIOUS_PLAY_ROUNDS: usize = 4000;
+
+/// Equilibrium mixed strategy and value for player 0 in a simultaneous-move
+/// subgame.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct NashResult {
+    /// Player 0's legal moves, in the same order as `probabilities`.
+    #[pyo3(get)]
+    pub moves: Vec<usize>,
+    /// Equilibrium probability of playing each move in `moves`, summing to 1.
+    #[pyo3(get)]
+    pub probabilities: Vec<f64>,
+    /// Game value from player 0's perspective, in units of the `Solver`'s
+    /// evaluation (store difference, plus its seed/mobility heuristic terms
+    /// wherever the search stops at the depth cutoff).
+    #[pyo3(get)]
+    pub value: f64,
+}
+
+/// Legal moves for `player`, independent of `state.current_player` — needed
+/// here because both seats choose at once in `SimultaneousIndependent` play.
+fn legal_moves_for(state: &BoardState, player: u8) -> Vec<usize> {
+    let pits = if player == 0 { P0_PITS } else { P1_PITS };
+    pits.filter(|&i| state.pits[i] > 0).collect()
+}
+
+/// Payoff of the (p0_move, p1_move) cell from player 0's perspective: play
+/// both moves out, then search `depth` more ply with the alpha-beta
+/// `Solver` from whoever moves next, flipping the result onto player 0's
+/// axis regardless of whose turn the resulting position says it is.
+fn payoff(
+    state: &BoardState,
+    p0_move: usize,
+    p1_move: usize,
+    rules: &RuleConfig,
+    depth: u32,
+    solver: &mut Solver,
+) -> f64 {
+    let result = apply_simultaneous_moves(state, p0_move, p1_move, rules);
+    let (_, value) = solver.best_move(&result.state, rules, depth, None);
+    if result.state.current_player == 0 {
+        value
+    } else {
+        -value
+    }
+}
+
+/// Row player's mixed strategy and the game value, solved by fictitious
+/// play over a zero-sum payoff matrix (row maximizes, column minimizes).
+struct MatrixGameSolution {
+    row_probabilities: Vec<f64>,
+    value: f64,
+}
+
+/// Solve a zero-sum matrix game `matrix[row][col]` (row player's payoff) via
+/// fictitious play: each round, both players best-respond to the other's
+/// empirical play so far, and the running average of row's realized
+/// strategy converges to an equilibrium (Robinson 1951). Dependency-free
+/// fallback for the LP formulation (`maximize v s.t. x >= 0, sum(x) = 1,
+/// x^T M >= v`), which this crate has no solver for.
+fn solve_matrix_game(matrix: &[Vec<f64>]) -> MatrixGameSolution {
+    let rows = matrix.len();
+    let cols = matrix[0].len();
+
+    let mut row_counts = vec![0u32; rows];
+    let mut row_cum_payoff = vec![0f64; rows]; // payoff to row if it had always played i, given col's actual plays
+    let mut col_cum_payoff = vec![0f64; cols]; // payoff to col if it had always played j, given row's actual plays
+    let mut total_value = 0f64;
+
+    for _ in 0..FICTITIOUS_PLAY_ROUNDS {
+        let best_row = (0..rows)
+            .max_by(|&a, &b| row_cum_payoff[a].partial_cmp(&row_cum_payoff[b]).unwrap())
+            .unwrap();
+        let best_col = (0..cols)
+            .max_by(|&a, &b| col_cum_payoff[a].partial_cmp(&col_cum_payoff[b]).unwrap())
+            .unwrap();
+
+        row_counts[best_row] += 1;
+        total_value += matrix[best_row][best_col];
+
+        for (row, payoff) in row_cum_payoff.iter_mut().enumerate() {
+            *payoff += matrix[row][best_col];
+        }
+        for (col, payoff) in col_cum_payoff.iter_mut().enumerate() {
+            *payoff -= matrix[best_row][col];
+        }
+    }
+
+    let row_probabilities = row_counts
+        .iter()
+        .map(|&count| count as f64 / FICTITIOUS_PLAY_ROUNDS as f64)
+        .collect();
+    let value = total_value / FICTITIOUS_PLAY_ROUNDS as f64;
+
+    MatrixGameSolution { row_probabilities, value }
+}
+
+/// Solve the `SimultaneousIndependent` subgame at `state` for player 0's
+/// equilibrium mixed strategy. Builds the payoff matrix `M[i][j]`, player
+/// 0's `Solver` evaluation, for every (p0 move, p1 move) pair —
+/// each cell evaluated by playing both moves out and then recursing with
+/// the alpha-beta `Solver` to `depth` more ply — and solves the resulting
+/// zero-sum matrix game by fictitious play. Returns player 0's moves, their
+/// equilibrium probabilities, and the game value, so a caller can sample a
+/// move from the returned distribution.
+#[pyfunction]
+pub fn solve_simultaneous(state: &BoardState, rules: &RuleConfig, depth: u32) -> NashResult {
+    let p0_moves = legal_moves_for(state, 0);
+    let p1_moves = legal_moves_for(state, 1);
+
+    if p0_moves.is_empty() || p1_moves.is_empty() {
+        let probabilities = vec![1.0 / p0_moves.len().max(1) as f64; p0_moves.len()];
+        return NashResult { moves: p0_moves, probabilities, value: 0.0 };
+    }
+
+    let mut solver = Solver::new();
+    let matrix: Vec<Vec<f64>> = p0_moves
+        .iter()
+        .map(|&p0_move| {
+            p1_moves
+                .iter()
+                .map(|&p1_move| payoff(state, p0_move, p1_move, rules, depth, &mut solver))
+                .collect()
+        })
+        .collect();
+
+    let solution = solve_matrix_game(&matrix);
+
+    NashResult {
+        moves: p0_moves,
+        probabilities: solution.row_probabilities,
+        value: solution.value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Matching pennies: row wins a point by matching column's choice, loses
+    /// a point otherwise. The unique equilibrium is 50/50 for both sides
+    /// with a game value of 0 — a simple, hand-computed check on the
+    /// fictitious-play bookkeeping's sign conventions (row maximizing its
+    /// own cumulative payoff, column minimizing row's, i.e. maximizing its
+    /// own negated payoff).
+    #[test]
+    fn test_solve_matrix_game_matching_pennies() {
+        let matrix = vec![vec![1.0, -1.0], vec![-1.0, 1.0]];
+
+        let solution = solve_matrix_game(&matrix);
+
+        let probability_sum: f64 = solution.row_probabilities.iter().sum();
+        assert!((probability_sum - 1.0).abs() < 1e-9, "probabilities must sum to 1, got {probability_sum}");
+        assert!(solution.value.abs() < 0.05, "expected a ~0 game value, got {}", solution.value);
+        assert!(
+            (solution.row_probabilities[0] - 0.5).abs() < 0.05,
+            "expected a ~50/50 mixed strategy, got {:?}",
+            solution.row_probabilities
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::board::{BoardState, INITIAL_SEEDS, P0_PITS, P0_STORE, P1_PITS, P1_STORE};
+use pyo3::prelude::*;
+use std::ops::Range;
+
+/// Outcome of starting the next round ("babak") of a burnt-holes match.
+#[pyclass]
+#[derive(Clone, Copy, Debug)]
+pub struct RoundResult {
+    #[pyo3(get)]
+    pub state: BoardState,
+    /// True once a player couldn't fill even one pit this round — the match
+    /// is over and `state` should not be played further.
+    #[pyo3(get)]
+    pub match_over: bool,
+}
+
+/// Refill one player's pits left-to-right from their own store, `INITIAL_SEEDS`
+/// at a time. Any seeds still sitting in this player's (not-yet-burnt) pits
+/// at round end are swept into the available pool first, so seeds are
+/// conserved even when only one side emptied out this round. A pit that's
+/// already burnt is left alone; a pit that can't be filled in full is burnt
+/// and left empty. Once the store can no longer fill a pit, every remaining
+/// not-yet-burnt pit for this player is burnt too (there's nothing left to
+/// fill them with). Returns the seeds left over once every pit has been
+/// filled or burnt, to keep in the store (fewer than `INITIAL_SEEDS` if any
+/// pit was burnt by this call).
+fn refill_player(pits: &mut [u8; 16], burnt: &mut [bool; 14], range: Range<usize>, store: usize) -> u8 {
+    let mut available = pits[store];
+    for pit in range.clone() {
+        if !burnt[pit] {
+            available += pits[pit];
+        }
+    }
+    for pit in range {
+        if burnt[pit] {
+            continue;
+        }
+        if available >= INITIAL_SEEDS {
+            pits[pit] = INITIAL_SEEDS;
+            available -= INITIAL_SEEDS;
+        } else {
+            burnt[pit] = true;
+            pits[pit] = 0;
+        }
+    }
+    available
+}
+
+/// Start the next round of a burnt-holes match. Call this once
+/// `is_terminal(state)` is true and `RuleConfig::burnt_holes_enabled` is set:
+/// each player's store is emptied back out into their pits, and
+/// `match_over` tells the caller whether either player ran out of pits to
+/// refill (in which case the match has ended and this round shouldn't be
+/// played).
+#[pyfunction]
+pub fn start_next_round(state: &BoardState) -> RoundResult {
+    let mut pits = state.pits;
+    let mut burnt = state.burnt;
+
+    pits[P0_STORE] = refill_player(&mut pits, &mut burnt, P0_PITS, P0_STORE);
+    pits[P1_STORE] = refill_player(&mut pits, &mut burnt, P1_PITS, P1_STORE);
+
+    let p0_has_live_pit = P0_PITS.clone().any(|i| !burnt[i]);
+    let p1_has_live_pit = P1_PITS.clone().any(|i| !burnt[i]);
+
+    RoundResult {
+        state: BoardState {
+            pits,
+            current_player: state.current_player,
+            burnt,
+        },
+        match_over: !p0_has_live_pit || !p1_has_live_pit,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refill_conserves_seeds_left_in_pits() {
+        // Round ended because P0's pits emptied out, but P1 still has seeds
+        // sitting in its pits (only reached the store once more recently).
+        let mut pits = [0u8; 16];
+        pits[P0_STORE] = 14; // two pits' worth
+        pits[7] = 3;
+        pits[9] = 5;
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
+
+        let total_before = state.total_seeds();
+        let result = start_next_round(&state);
+        let total_after = result.state.total_seeds();
+
+        assert_eq!(total_before, total_after, "refilling must conserve total seeds on the board");
+
+        // P1's leftover 8 seeds (3 + 5) fill pit 7 and burn the rest, with 1 left in the store.
+        assert_eq!(result.state.pits[7], INITIAL_SEEDS);
+        assert_eq!(result.state.pits[P1_STORE], 1);
+        assert!(result.state.burnt[8]);
+    }
+
+    #[test]
+    fn test_refill_leaves_burnt_pits_alone() {
+        let mut pits = [0u8; 16];
+        pits[P0_STORE] = 7;
+        let mut burnt = [false; 14];
+        burnt[1] = true;
+        let state = BoardState::from_pits(pits, 0, burnt);
+
+        let result = start_next_round(&state);
+
+        // pit 1 stays burnt and empty even though there were enough seeds to fill pit 0.
+        assert!(result.state.burnt[1]);
+        assert_eq!(result.state.pits[1], 0);
+        assert_eq!(result.state.pits[0], INITIAL_SEEDS);
+    }
+
+    #[test]
+    fn test_match_over_when_a_side_cannot_refill_any_pit() {
+        let mut pits = [0u8; 16];
+        pits[P0_STORE] = 3; // not enough to fill even one pit
+        pits[P1_STORE] = 14;
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
+
+        let result = start_next_round(&state);
+
+        assert!(result.match_over);
+        assert!(P0_PITS.clone().all(|i| result.state.burnt[i]));
+    }
+}
@@ -45,13 +45,26 @@ pub struct RuleConfig {
     #[pyo3(get, set)]
     pub forfeit_enabled: bool,
 
-    /// Multi-round play with burnt holes (not implemented yet)
+    /// Multi-round ("New Year"/babak) play: once a round ends (one side's
+    /// pits are empty), each player refills their pits from their own store
+    /// left to right, 7 seeds at a time. A pit that can't be fully refilled
+    /// is "burnt" (`BoardState::burnt`) for the rest of the match: it's
+    /// skipped during sowing and can't receive or capture seeds again.
+    /// See `rounds::start_next_round`.
     #[pyo3(get, set)]
     pub burnt_holes_enabled: bool,
 
     /// Capture only allowed after passing through own store at least once
     #[pyo3(get, set)]
     pub capture_requires_loop: bool,
+
+    /// Seed for `LeaderSelection::Random` and any other stochastic rule
+    /// decision, so a game (or a replicate in a batch) can be reproduced
+    /// byte-for-byte. `None` means "don't care", and falls back to a fixed
+    /// default seed rather than true randomness — use `generate_seeds` plus
+    /// `with_seed` to get independent seeds across a batch of replicates.
+    #[pyo3(get, set)]
+    pub seed: Option<u64>,
 }
 
 #[pymethods]
@@ -63,7 +76,8 @@ impl RuleConfig {
         capture_enabled=true,
         forfeit_enabled=true,
         burnt_holes_enabled=false,
-        capture_requires_loop=false
+        capture_requires_loop=false,
+        seed=None
     ))]
     pub fn new(
         start_mode: StartMode,
@@ -72,6 +86,7 @@ impl RuleConfig {
         forfeit_enabled: bool,
         burnt_holes_enabled: bool,
         capture_requires_loop: bool,
+        seed: Option<u64>,
     ) -> Self {
         RuleConfig {
             start_mode,
@@ -80,6 +95,7 @@ impl RuleConfig {
             forfeit_enabled,
             burnt_holes_enabled,
             capture_requires_loop,
+            seed,
         }
     }
 
@@ -92,18 +108,47 @@ impl RuleConfig {
             forfeit_enabled: true,
             burnt_holes_enabled: false,
             capture_requires_loop: false,
+            seed: None,
+        }
+    }
+
+    /// Return a copy of this config with `seed` set, for running one
+    /// replicate of a batch at a fixed seed while sharing every other rule.
+    pub fn with_seed(&self, seed: u64) -> Self {
+        RuleConfig { seed: Some(seed), ..*self }
+    }
+
+    /// Pick the leader for `SimultaneousLeaderFollower` play (0 or 1),
+    /// deterministic in `self.seed` so the same config always picks the
+    /// same leader. `AlwaysP0`/`AlwaysP1` ignore the seed entirely.
+    pub fn select_leader(&self) -> u8 {
+        match self.leader_selection {
+            LeaderSelection::AlwaysP0 => 0,
+            LeaderSelection::AlwaysP1 => 1,
+            LeaderSelection::Random => {
+                // simple xorshift, mirroring the PRNG used by `random_playout`
+                let mut state = match self.seed {
+                    Some(0) | None => 0x9E3779B97F4A7C15,
+                    Some(seed) => seed,
+                };
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state & 1) as u8
+            }
         }
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "RuleConfig(start_mode={:?}, leader_selection={:?}, capture={}, forfeit={}, burnt_holes={}, capture_requires_loop={})",
+            "RuleConfig(start_mode={:?}, leader_selection={:?}, capture={}, forfeit={}, burnt_holes={}, capture_requires_loop={}, seed={:?})",
             self.start_mode,
             self.leader_selection,
             self.capture_enabled,
             self.forfeit_enabled,
             self.burnt_holes_enabled,
-            self.capture_requires_loop
+            self.capture_requires_loop,
+            self.seed
         )
     }
 }
@@ -113,3 +158,33 @@ impl Default for RuleConfig {
         Self::default_rules()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_leader_is_deterministic_for_a_given_seed() {
+        let rules = RuleConfig { seed: Some(42), ..RuleConfig::default() };
+        assert_eq!(rules.select_leader(), rules.select_leader());
+    }
+
+    #[test]
+    fn test_select_leader_diverges_across_seeds() {
+        let leaders: Vec<u8> = (0..10)
+            .map(|seed| RuleConfig { seed: Some(seed), ..RuleConfig::default() }.select_leader())
+            .collect();
+        assert!(
+            leaders.contains(&0) && leaders.contains(&1),
+            "expected both leaders to show up across seeds, got {leaders:?}"
+        );
+    }
+
+    #[test]
+    fn test_select_leader_always_p0_and_always_p1_ignore_the_seed() {
+        let always_p0 = RuleConfig { leader_selection: LeaderSelection::AlwaysP0, seed: Some(999), ..RuleConfig::default() };
+        let always_p1 = RuleConfig { leader_selection: LeaderSelection::AlwaysP1, seed: Some(999), ..RuleConfig::default() };
+        assert_eq!(always_p0.select_leader(), 0);
+        assert_eq!(always_p1.select_leader(), 1);
+    }
+}
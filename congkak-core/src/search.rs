@@ -0,0 +1,562 @@
+use crate::board::BoardState;
+use crate::moves::{apply_move, get_legal_moves, get_winner, is_terminal};
+use crate::rules::RuleConfig;
+use crate::simulation::random_playout;
+use pyo3::prelude::*;
+
+/// Per-move statistics produced by an MCTS search, suitable for picking a
+/// move or for inspecting the search (e.g. to compare against the endgame
+/// solver).
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct MctsResult {
+    /// Legal moves considered from the root, in the same order as `visits`/`values`.
+    #[pyo3(get)]
+    pub moves: Vec<usize>,
+    /// Visit count for each root move.
+    #[pyo3(get)]
+    pub visits: Vec<u32>,
+    /// Average backed-up value for each root move, from the root player's perspective.
+    #[pyo3(get)]
+    pub values: Vec<f64>,
+    /// The most-visited root move.
+    #[pyo3(get)]
+    pub best_move: usize,
+}
+
+/// One node in the search tree. `player_to_move` is whoever acts at this
+/// node; `visits`/`value` are always tracked from that player's perspective,
+/// which is what lets extra-turn chains (where the mover doesn't change)
+/// fall out of the same bookkeeping as a normal turn change.
+struct MctsNode {
+    state: BoardState,
+    player_to_move: u8,
+    untried_moves: Vec<usize>,
+    children: Vec<(usize, usize)>, // (move, child index in arena)
+    visits: u32,
+    value: f64,
+    terminal: bool,
+}
+
+impl MctsNode {
+    fn new(state: BoardState, rules: &RuleConfig) -> Self {
+        let terminal = crate::moves::is_terminal(&state);
+        let untried_moves = if terminal {
+            Vec::new()
+        } else {
+            get_legal_moves(&state)
+        };
+        let _ = rules; // legality doesn't depend on rules today, kept for symmetry with solver APIs
+        MctsNode {
+            player_to_move: state.current_player,
+            state,
+            untried_moves,
+            children: Vec::new(),
+            visits: 0,
+            value: 0.0,
+            terminal,
+        }
+    }
+}
+
+/// Result of a terminal/leaf evaluation (+1 win, 0 draw, -1 loss), from `player`'s perspective.
+fn result_for_player(winner: i8, player: u8) -> f64 {
+    if winner == -1 {
+        0.0
+    } else if winner as u8 == player {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+/// `child.value` is accumulated from `child.player_to_move`'s own
+/// perspective (see `MctsNode`'s doc comment); flip it onto `parent_player`'s
+/// axis so an extra-turn child (same mover as the parent) and a
+/// turn-changing child (opposing mover) compare on the same scale, mirroring
+/// the `-negamax(...)` convention in `endgame.rs`/`solver.rs`.
+fn child_q(child: &MctsNode, parent_player: u8) -> f64 {
+    if child.visits == 0 {
+        return 0.0;
+    }
+    let q = child.value / child.visits as f64;
+    if child.player_to_move == parent_player {
+        q
+    } else {
+        -q
+    }
+}
+
+fn uct_score(child: &MctsNode, parent_player: u8, parent_visits: u32, exploration: f64) -> f64 {
+    if child.visits == 0 {
+        return f64::INFINITY;
+    }
+    let exploit = child_q(child, parent_player);
+    let explore = exploration * ((parent_visits as f64).ln() / child.visits as f64).sqrt();
+    exploit + explore
+}
+
+fn select_child(nodes: &[MctsNode], idx: usize, exploration: f64) -> usize {
+    let node = &nodes[idx];
+    let parent_player = node.player_to_move;
+    let parent_visits = node.visits;
+    node.children
+        .iter()
+        .map(|&(_, child_idx)| child_idx)
+        .max_by(|&a, &b| {
+            uct_score(&nodes[a], parent_player, parent_visits, exploration)
+                .partial_cmp(&uct_score(&nodes[b], parent_player, parent_visits, exploration))
+                .unwrap()
+        })
+        .expect("select_child called on a node with no children")
+}
+
+/// Simple xorshift step, mirroring the PRNG already used by `random_playout`.
+fn next_seed(rng_state: &mut u64) -> u64 {
+    *rng_state ^= *rng_state << 13;
+    *rng_state ^= *rng_state >> 7;
+    *rng_state ^= *rng_state << 17;
+    *rng_state
+}
+
+/// Run one MCTS iteration starting from `idx`, returning the rollout winner
+/// (0, 1, or -1 for draw) so callers up the recursion can back it up from
+/// their own `player_to_move`'s perspective.
+fn iterate(
+    nodes: &mut Vec<MctsNode>,
+    idx: usize,
+    rules: &RuleConfig,
+    exploration: f64,
+    rng_state: &mut u64,
+) -> i8 {
+    if nodes[idx].terminal {
+        let winner = get_winner(&nodes[idx].state);
+        nodes[idx].visits += 1;
+        nodes[idx].value += result_for_player(winner, nodes[idx].player_to_move);
+        return winner;
+    }
+
+    if !nodes[idx].untried_moves.is_empty() {
+        let mv = nodes[idx].untried_moves.pop().unwrap();
+        let result = apply_move(&nodes[idx].state, mv, rules);
+        let child = MctsNode::new(result.state, rules);
+        let child_player = child.player_to_move;
+        let child_idx = nodes.len();
+        nodes.push(child);
+        nodes[idx].children.push((mv, child_idx));
+
+        let winner = if nodes[child_idx].terminal {
+            let w = get_winner(&nodes[child_idx].state);
+            nodes[child_idx].visits += 1;
+            nodes[child_idx].value += result_for_player(w, child_player);
+            w
+        } else {
+            let w = random_playout(&nodes[child_idx].state, rules, next_seed(rng_state));
+            nodes[child_idx].visits += 1;
+            nodes[child_idx].value += result_for_player(w, child_player);
+            w
+        };
+
+        nodes[idx].visits += 1;
+        nodes[idx].value += result_for_player(winner, nodes[idx].player_to_move);
+        return winner;
+    }
+
+    let child_idx = select_child(nodes, idx, exploration);
+    let winner = iterate(nodes, child_idx, rules, exploration, rng_state);
+    nodes[idx].visits += 1;
+    nodes[idx].value += result_for_player(winner, nodes[idx].player_to_move);
+    winner
+}
+
+/// Run a UCT/MCTS search from `state` for `iterations` rollouts and return
+/// per-move visit counts and value estimates.
+#[pyfunction]
+pub fn mcts_search(
+    state: &BoardState,
+    rules: &RuleConfig,
+    iterations: u32,
+    exploration: f64,
+) -> MctsResult {
+    let mut nodes = vec![MctsNode::new(*state, rules)];
+    let mut rng_state = 0x9E3779B97F4A7C15u64 ^ (iterations as u64).wrapping_mul(2654435761);
+
+    for _ in 0..iterations {
+        iterate(&mut nodes, 0, rules, exploration, &mut rng_state);
+        if nodes[0].untried_moves.is_empty() && nodes[0].children.is_empty() {
+            // root is terminal or has no legal moves; nothing more to search.
+            break;
+        }
+    }
+
+    let mut moves = Vec::new();
+    let mut visits = Vec::new();
+    let mut values = Vec::new();
+    let mut best_move = 0usize;
+    let mut best_visits = -1i64;
+
+    let root_player = nodes[0].player_to_move;
+    for &(mv, child_idx) in &nodes[0].children {
+        let child = &nodes[child_idx];
+        moves.push(mv);
+        visits.push(child.visits);
+        values.push(child_q(child, root_player));
+        if child.visits as i64 > best_visits {
+            best_visits = child.visits as i64;
+            best_move = mv;
+        }
+    }
+
+    MctsResult {
+        moves,
+        visits,
+        values,
+        best_move,
+    }
+}
+
+/// Run an MCTS search and return only the root child with the highest visit count.
+#[pyfunction]
+pub fn mcts_best_move(
+    state: &BoardState,
+    rules: &RuleConfig,
+    iterations: u32,
+    exploration: f64,
+) -> usize {
+    mcts_search(state, rules, iterations, exploration).best_move
+}
+
+/// Map a board pit index to the policy head's local 0..6 slot, which is the
+/// same regardless of which player owns it.
+fn local_move_index(pit: usize, player: u8) -> usize {
+    if player == 0 {
+        pit
+    } else {
+        pit - 7
+    }
+}
+
+/// Call the Python `(state) -> (policy: [f32; 7], value: f32)` evaluator.
+fn call_evaluator(
+    py: Python<'_>,
+    evaluator: &Py<PyAny>,
+    state: &BoardState,
+) -> PyResult<([f32; 7], f32)> {
+    let state_obj = Py::new(py, *state)?;
+    let result = evaluator.bind(py).call1((state_obj,))?;
+    result.extract()
+}
+
+/// A node in the PUCT tree. Like `MctsNode`, `visits`/`value` are tracked
+/// from `player_to_move`'s own perspective, which is what lets `extra_turn`
+/// continuations share the same backprop code as a normal turn change.
+struct PuctNode {
+    state: BoardState,
+    player_to_move: u8,
+    terminal: bool,
+    expanded: bool,
+    children: Vec<(usize, f32, usize)>, // (move, prior, child index in arena)
+    visits: u32,
+    value: f64,
+}
+
+impl PuctNode {
+    fn new(state: BoardState) -> Self {
+        let terminal = is_terminal(&state);
+        PuctNode {
+            player_to_move: state.current_player,
+            terminal,
+            expanded: false,
+            children: Vec::new(),
+            state,
+            visits: 0,
+            value: 0.0,
+        }
+    }
+}
+
+/// `child_value`/`child_visits` are accumulated from the child's own
+/// `player_to_move` perspective; flip the exploitation term onto
+/// `parent_player`'s axis before comparing siblings, exactly like
+/// `child_q` does for UCT.
+fn puct_score(
+    child_prior: f32,
+    child_visits: u32,
+    child_value: f64,
+    child_player: u8,
+    parent_player: u8,
+    parent_visits: u32,
+    c_puct: f64,
+) -> f64 {
+    let q = if child_visits > 0 {
+        let raw = child_value / child_visits as f64;
+        if child_player == parent_player { raw } else { -raw }
+    } else {
+        0.0
+    };
+    let explore = c_puct * child_prior as f64 * (parent_visits as f64).sqrt() / (1.0 + child_visits as f64);
+    q + explore
+}
+
+fn select_puct_child(nodes: &[PuctNode], idx: usize, c_puct: f64) -> usize {
+    let node = &nodes[idx];
+    let parent_player = node.player_to_move;
+    let parent_visits = node.visits;
+    node.children
+        .iter()
+        .map(|&(_, prior, child_idx)| (prior, child_idx))
+        .max_by(|&(prior_a, a), &(prior_b, b)| {
+            let score_a = puct_score(
+                prior_a,
+                nodes[a].visits,
+                nodes[a].value,
+                nodes[a].player_to_move,
+                parent_player,
+                parent_visits,
+                c_puct,
+            );
+            let score_b = puct_score(
+                prior_b,
+                nodes[b].visits,
+                nodes[b].value,
+                nodes[b].player_to_move,
+                parent_player,
+                parent_visits,
+                c_puct,
+            );
+            score_a.partial_cmp(&score_b).unwrap()
+        })
+        .map(|(_, child_idx)| child_idx)
+        .expect("select_puct_child called on a node with no children")
+}
+
+/// Run one PUCT iteration from `idx`, returning the outcome from `idx`'s own
+/// `player_to_move`'s perspective (same convention as `MctsNode`/`iterate`),
+/// so the caller flips it exactly when the mover changes, mirroring the
+/// `-negamax(...)` convention in `endgame.rs`/`solver.rs`.
+fn puct_iterate(
+    py: Python<'_>,
+    nodes: &mut Vec<PuctNode>,
+    idx: usize,
+    rules: &RuleConfig,
+    c_puct: f64,
+    evaluator: &Py<PyAny>,
+) -> PyResult<f64> {
+    if nodes[idx].terminal {
+        let winner = get_winner(&nodes[idx].state);
+        let value = result_for_player(winner, nodes[idx].player_to_move);
+        nodes[idx].visits += 1;
+        nodes[idx].value += value;
+        return Ok(value);
+    }
+
+    if !nodes[idx].expanded {
+        let leaf_player = nodes[idx].player_to_move;
+        let (policy, value) = call_evaluator(py, evaluator, &nodes[idx].state)?;
+        let legal = get_legal_moves(&nodes[idx].state);
+
+        let mut prior_sum = 0.0f32;
+        let mut priors = Vec::with_capacity(legal.len());
+        for &mv in &legal {
+            let p = policy[local_move_index(mv, leaf_player)].max(0.0);
+            priors.push(p);
+            prior_sum += p;
+        }
+        if prior_sum <= 0.0 {
+            // degenerate/uniform policy: fall back to a flat prior
+            priors.iter_mut().for_each(|p| *p = 1.0 / legal.len() as f32);
+        } else {
+            priors.iter_mut().for_each(|p| *p /= prior_sum);
+        }
+
+        for (&mv, &prior) in legal.iter().zip(priors.iter()) {
+            let result = apply_move(&nodes[idx].state, mv, rules);
+            let child_idx = nodes.len();
+            nodes.push(PuctNode::new(result.state));
+            nodes[idx].children.push((mv, prior, child_idx));
+        }
+        nodes[idx].expanded = true;
+
+        let value = value as f64;
+        nodes[idx].visits += 1;
+        nodes[idx].value += value;
+        return Ok(value);
+    }
+
+    let child_idx = select_puct_child(nodes, idx, c_puct);
+    let child_value = puct_iterate(py, nodes, child_idx, rules, c_puct, evaluator)?;
+    let value = if nodes[child_idx].player_to_move == nodes[idx].player_to_move {
+        child_value
+    } else {
+        -child_value
+    };
+    nodes[idx].visits += 1;
+    nodes[idx].value += value;
+    Ok(value)
+}
+
+/// Run a PUCT search guided by a Python `(state) -> (policy, value)`
+/// evaluator and return per-move visit counts and value estimates, in the
+/// same shape as `mcts_search`.
+#[pyfunction]
+pub fn puct_search(
+    py: Python<'_>,
+    state: &BoardState,
+    rules: &RuleConfig,
+    iterations: u32,
+    c_puct: f64,
+    evaluator: Py<PyAny>,
+) -> PyResult<MctsResult> {
+    let mut nodes = vec![PuctNode::new(*state)];
+
+    for _ in 0..iterations {
+        puct_iterate(py, &mut nodes, 0, rules, c_puct, &evaluator)?;
+        if nodes[0].terminal {
+            break;
+        }
+    }
+
+    let mut moves = Vec::new();
+    let mut visits = Vec::new();
+    let mut values = Vec::new();
+    let mut best_move = 0usize;
+    let mut best_visits = -1i64;
+
+    let root_player = nodes[0].player_to_move;
+    for &(mv, _prior, child_idx) in &nodes[0].children {
+        let child = &nodes[child_idx];
+        moves.push(mv);
+        visits.push(child.visits);
+        let raw = if child.visits > 0 {
+            child.value / child.visits as f64
+        } else {
+            0.0
+        };
+        values.push(if child.player_to_move == root_player { raw } else { -raw });
+        if child.visits as i64 > best_visits {
+            best_visits = child.visits as i64;
+            best_move = mv;
+        }
+    }
+
+    Ok(MctsResult {
+        moves,
+        visits,
+        values,
+        best_move,
+    })
+}
+
+/// One ply of a self-play game: the move taken and the root's visit-count
+/// distribution over the 7 local policy slots, for use as a training target.
+#[pyclass]
+#[derive(Clone, Debug)]
+pub struct SelfPlayStep {
+    #[pyo3(get)]
+    pub player: u8,
+    #[pyo3(get)]
+    pub mv: usize,
+    #[pyo3(get)]
+    pub visit_distribution: [f32; 7],
+}
+
+/// Play a full game against itself using `puct_search` at every ply, and
+/// return the move sequence plus per-ply visit-count distributions so the
+/// trajectory can be used as AlphaZero-style training targets.
+#[pyfunction]
+pub fn self_play_game(
+    py: Python<'_>,
+    state: &BoardState,
+    rules: &RuleConfig,
+    iterations: u32,
+    c_puct: f64,
+    evaluator: Py<PyAny>,
+    max_plies: u32,
+) -> PyResult<Vec<SelfPlayStep>> {
+    let mut current = *state;
+    let mut steps = Vec::new();
+
+    for _ in 0..max_plies {
+        if is_terminal(&current) {
+            break;
+        }
+
+        let result = puct_search(py, &current, rules, iterations, c_puct, evaluator.clone_ref(py))?;
+        let player = current.current_player;
+        let total_visits: u32 = result.visits.iter().sum();
+        let mut visit_distribution = [0.0f32; 7];
+        for (&mv, &visits) in result.moves.iter().zip(result.visits.iter()) {
+            let local = local_move_index(mv, player);
+            visit_distribution[local] = if total_visits > 0 {
+                visits as f32 / total_visits as f32
+            } else {
+                0.0
+            };
+        }
+
+        steps.push(SelfPlayStep {
+            player,
+            mv: result.best_move,
+            visit_distribution,
+        });
+
+        current = apply_move(&current, result.best_move, rules).state;
+    }
+
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::RuleConfig;
+
+    /// Player 0 to move with exactly two legal pits: pit 1's single seed
+    /// lands in the (empty) pit 0 and captures pit 13's whole stash,
+    /// emptying player 1's side outright (an immediate, decisive win); pit
+    /// 4's single seed just lands in the empty pit 3 with nothing opposite
+    /// to capture, leaving a normal, still-contested position. Both moves
+    /// pass the turn (neither lands in the mover's own store), so getting
+    /// the root decision right here depends on flipping a child's backed-up
+    /// value onto the root player's perspective exactly when the turn
+    /// actually changes — the same sign logic `child_q`/`puct_score` got
+    /// wrong before being fixed.
+    fn decisive_vs_neutral_capture_state() -> BoardState {
+        let mut pits = [0u8; 16];
+        pits[1] = 1;
+        pits[4] = 1;
+        pits[13] = 6;
+        BoardState::from_pits(pits, 0, [false; 14])
+    }
+
+    #[test]
+    fn test_mcts_prefers_the_decisive_capture_over_a_neutral_move() {
+        let state = decisive_vs_neutral_capture_state();
+        let rules = RuleConfig::default();
+
+        let best_move = mcts_best_move(&state, &rules, 300, 1.4);
+        assert_eq!(best_move, 1);
+    }
+
+    /// Uniform-policy, constant-value stub evaluator: priors don't favor
+    /// either move, and the non-terminal leaf's value (0.0) never grows, so
+    /// the decisive move's exact +1 terminal backprop is the only thing
+    /// that can make PUCT prefer it.
+    #[pyfunction]
+    fn stub_evaluator(_state: BoardState) -> ([f32; 7], f32) {
+        ([1.0 / 7.0; 7], 0.0)
+    }
+
+    #[test]
+    fn test_puct_prefers_the_decisive_capture_over_a_neutral_move() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let state = decisive_vs_neutral_capture_state();
+            let rules = RuleConfig::default();
+            let evaluator: Py<PyAny> = wrap_pyfunction!(stub_evaluator, py).unwrap().into();
+
+            let result = puct_search(py, &state, &rules, 300, 1.5, evaluator).unwrap();
+            assert_eq!(result.best_move, 1);
+        });
+    }
+}
@@ -50,6 +50,24 @@ pub fn batch_random_playouts(
     counts
 }
 
+/// Generate `count` independent seeds derived from `base_seed`, for running
+/// `count` replicated games that are each individually reproducible (e.g.
+/// via `RuleConfig::with_seed`) without all colliding on the same stream.
+#[pyfunction]
+pub fn generate_seeds(base_seed: u64, count: u32) -> Vec<u64> {
+    let mut seeds = Vec::with_capacity(count as usize);
+    let mut rng_state = if base_seed == 0 { 0x9E3779B97F4A7C15 } else { base_seed };
+
+    for _ in 0..count {
+        rng_state ^= rng_state << 13;
+        rng_state ^= rng_state >> 7;
+        rng_state ^= rng_state << 17;
+        seeds.push(rng_state);
+    }
+
+    seeds
+}
+
 /// Perft: count positions at depth (for debugging move generation)
 #[pyfunction]
 pub fn perft(state: &BoardState, rules: &RuleConfig, depth: u32) -> u64 {
@@ -67,3 +85,26 @@ pub fn perft(state: &BoardState, rules: &RuleConfig, depth: u32) -> u64 {
 
     count
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_seeds_is_deterministic_for_a_given_base_seed() {
+        assert_eq!(generate_seeds(7, 5), generate_seeds(7, 5));
+    }
+
+    #[test]
+    fn test_generate_seeds_diverges_across_base_seeds() {
+        assert_ne!(generate_seeds(1, 5), generate_seeds(2, 5));
+    }
+
+    #[test]
+    fn test_generate_seeds_produces_the_requested_count_with_no_duplicates() {
+        let seeds = generate_seeds(123, 16);
+        assert_eq!(seeds.len(), 16);
+        let unique: std::collections::HashSet<_> = seeds.iter().collect();
+        assert_eq!(unique.len(), seeds.len(), "expected independent seeds, got {seeds:?}");
+    }
+}
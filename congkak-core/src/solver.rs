@@ -0,0 +1,279 @@
+use crate::board::{BoardState, P0_PITS, P0_STORE, P1_PITS, P1_STORE};
+use crate::moves::{apply_move, get_final_scores, get_legal_moves, is_terminal};
+use crate::rules::RuleConfig;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// What a transposition-table entry's stored value actually bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TtEntry {
+    value: f64,
+    depth: u32,
+    bound: Bound,
+}
+
+/// Weight on seeds still sitting in my own pits in the heuristic eval: small
+/// relative to the store difference so it only breaks ties between
+/// near-equal lines rather than overriding material already banked.
+const SEED_WEIGHT: f64 = 0.1;
+/// Weight on mobility (legal move count) in the heuristic eval.
+const MOBILITY_WEIGHT: f64 = 0.05;
+
+/// Leaf evaluation from `state.current_player`'s perspective. At a terminal
+/// position this is the exact store difference (`get_final_scores` folds in
+/// uncollected pit seeds); at a depth-cutoff it's a heuristic blend of store
+/// difference, seeds still on my side, and mobility, since the game isn't
+/// actually over yet.
+fn evaluate(state: &BoardState) -> f64 {
+    if is_terminal(state) {
+        let (p0, p1) = get_final_scores(state);
+        return if state.current_player == 0 {
+            p0 as f64 - p1 as f64
+        } else {
+            p1 as f64 - p0 as f64
+        };
+    }
+
+    let (my_store, opp_store, my_pits) = if state.current_player == 0 {
+        (P0_STORE, P1_STORE, P0_PITS)
+    } else {
+        (P1_STORE, P0_STORE, P1_PITS)
+    };
+    let store_diff = state.pits[my_store] as f64 - state.pits[opp_store] as f64;
+    let my_seeds: f64 = state.pits[my_pits].iter().map(|&s| s as f64).sum();
+    let mobility = get_legal_moves(state).len() as f64;
+
+    store_diff + SEED_WEIGHT * my_seeds + MOBILITY_WEIGHT * mobility
+}
+
+/// Bundles the two pieces of search state that every `negamax` call threads
+/// down unchanged (only `alpha`/`beta`/`depth` actually narrow per call) —
+/// keeping them out of `negamax`'s own argument list.
+struct SearchContext<'a> {
+    tt: &'a mut HashMap<u64, TtEntry>,
+    deadline: Option<Instant>,
+}
+
+/// Negamax with alpha-beta pruning, transposition-tabled on `hash` — the
+/// Zobrist key for `state`, threaded down from each move's `MoveResult.zobrist`
+/// so a node never calls `state.zobrist()` for its own key. A move
+/// with `extra_turn` keeps the same player to move, so its value is *not*
+/// negated — it's a continuation for the same side rather than a turn
+/// change. Once `ctx.deadline` passes, the search is cut short at the
+/// current node by falling back to the heuristic leaf evaluation, same as
+/// running out of depth — but since `ctx.tt` persists across
+/// `Solver::best_move` calls, a value produced (directly or via a
+/// descendant) after the deadline tripped is NOT a genuine depth-`depth`
+/// result and must not be cached as one, or a later, unhurried search could
+/// read it back as if it were. Returns `(value, truncated)`.
+fn negamax(
+    state: &BoardState,
+    hash: u64,
+    rules: &RuleConfig,
+    depth: u32,
+    mut alpha: f64,
+    mut beta: f64,
+    ctx: &mut SearchContext,
+) -> (f64, bool) {
+    let key = hash;
+    if let Some(entry) = ctx.tt.get(&key) {
+        if entry.depth >= depth {
+            match entry.bound {
+                Bound::Exact => return (entry.value, false),
+                Bound::Lower => alpha = alpha.max(entry.value),
+                Bound::Upper => beta = beta.min(entry.value),
+            }
+            if alpha >= beta {
+                return (entry.value, false);
+            }
+        }
+    }
+
+    if depth == 0 || is_terminal(state) {
+        return (evaluate(state), false);
+    }
+    if ctx.deadline.is_some_and(|d| Instant::now() >= d) {
+        return (evaluate(state), true);
+    }
+
+    let moves = get_legal_moves(state);
+    let original_alpha = alpha;
+    let mut best = f64::NEG_INFINITY;
+    let mut truncated = false;
+
+    for mv in moves {
+        let result = apply_move(state, mv, rules);
+        let value = if result.extra_turn {
+            let (v, t) = negamax(&result.state, result.zobrist, rules, depth - 1, alpha, beta, ctx);
+            truncated |= t;
+            v
+        } else {
+            let (v, t) = negamax(&result.state, result.zobrist, rules, depth - 1, -beta, -alpha, ctx);
+            truncated |= t;
+            -v
+        };
+
+        if value > best {
+            best = value;
+        }
+        if best > alpha {
+            alpha = best;
+        }
+        if alpha >= beta {
+            break;
+        }
+    }
+
+    if !truncated {
+        let bound = if best <= original_alpha {
+            Bound::Upper
+        } else if best >= beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        ctx.tt.insert(key, TtEntry { value: best, depth, bound });
+    }
+
+    (best, truncated)
+}
+
+/// A depth-limited alpha-beta engine with a transposition table that
+/// persists across `best_move` calls, so repeated positions (e.g. across a
+/// self-play loop) aren't re-searched from scratch. Unlike `endgame::solve`,
+/// this searches to a fixed depth with a heuristic leaf evaluation rather
+/// than playing the game out exactly, which makes it usable as a baseline
+/// agent on positions too deep to solve exhaustively.
+#[pyclass]
+pub struct Solver {
+    tt: HashMap<u64, TtEntry>,
+}
+
+#[pymethods]
+impl Solver {
+    #[new]
+    pub fn new() -> Self {
+        Solver { tt: HashMap::new() }
+    }
+
+    /// Search `state` to `depth` ply, honoring `rules` for capture/forfeit
+    /// behavior, and return `(best_move, value)` where `value` is the
+    /// evaluation from the current player's perspective. If
+    /// `time_budget_ms` is set, the search may cut individual branches
+    /// short once the budget elapses rather than guaranteeing the full
+    /// depth everywhere.
+    #[pyo3(signature = (state, rules, depth, time_budget_ms=None))]
+    pub fn best_move(
+        &mut self,
+        state: &BoardState,
+        rules: &RuleConfig,
+        depth: u32,
+        time_budget_ms: Option<u64>,
+    ) -> (usize, f64) {
+        let moves = get_legal_moves(state);
+        if moves.is_empty() {
+            return (0, evaluate(state));
+        }
+
+        let deadline = time_budget_ms.map(|ms| Instant::now() + Duration::from_millis(ms));
+        let mut ctx = SearchContext { tt: &mut self.tt, deadline };
+        let mut alpha = f64::NEG_INFINITY;
+        let beta = f64::INFINITY;
+        let mut best_move = moves[0];
+        let mut best_value = f64::NEG_INFINITY;
+
+        for mv in moves {
+            let result = apply_move(state, mv, rules);
+            let value = if result.extra_turn {
+                negamax(&result.state, result.zobrist, rules, depth.saturating_sub(1), alpha, beta, &mut ctx).0
+            } else {
+                -negamax(&result.state, result.zobrist, rules, depth.saturating_sub(1), -beta, -alpha, &mut ctx).0
+            };
+
+            if value > best_value {
+                best_value = value;
+                best_move = mv;
+            }
+            if value > alpha {
+                alpha = value;
+            }
+        }
+
+        (best_move, best_value)
+    }
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One-shot convenience wrapper around `Solver::best_move` for callers that
+/// don't need the transposition table to persist across searches.
+#[pyfunction]
+#[pyo3(signature = (state, rules, depth, time_budget_ms=None))]
+pub fn best_move(
+    state: &BoardState,
+    rules: &RuleConfig,
+    depth: u32,
+    time_budget_ms: Option<u64>,
+) -> (usize, f64) {
+    Solver::new().best_move(state, rules, depth, time_budget_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Player 0 to move with exactly two legal pits: pit 0's single seed
+    /// lands straight in the player's own store, granting an extra turn
+    /// with zero material cost; pit 6's 8 seeds sow through pits 5-0 and
+    /// the store into pit 13 (player 1's pit, empty), forfeiting that last seed to
+    /// player 1's store. The extra-turn move keeps the current player to
+    /// move, so `negamax` must NOT negate its returned value — if it did,
+    /// the tempo-neutral extra turn would score as a loss and the strictly
+    /// worse forfeiting move would look better.
+    #[test]
+    fn test_solver_prefers_extra_turn_tempo_over_forfeiting_a_seed() {
+        let mut pits = [0u8; 16];
+        pits[0] = 1;
+        pits[6] = 8;
+        pits[7..13].fill(7);
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
+        let rules = RuleConfig::default();
+
+        let (best_move, _) = Solver::new().best_move(&state, &rules, 4, None);
+        assert_eq!(best_move, 0);
+    }
+
+    /// Player 0 to move with exactly two legal pits: pit 1's single seed
+    /// lands in the (empty) pit 0 and captures pit 13's whole stash,
+    /// emptying player 1's side outright (an immediate, decisive win); pit
+    /// 4's single seed just lands in the empty pit 3 with nothing opposite
+    /// to capture, leaving a normal, still-contested position. Neither
+    /// move is an extra turn, so the root decision also depends on
+    /// `negamax` negating a turn-changing child's value onto the right
+    /// side of the root's alpha-beta window.
+    #[test]
+    fn test_solver_prefers_the_decisive_capture_over_a_neutral_move() {
+        let mut pits = [0u8; 16];
+        pits[1] = 1;
+        pits[4] = 1;
+        pits[13] = 6;
+        let state = BoardState::from_pits(pits, 0, [false; 14]);
+        let rules = RuleConfig::default();
+
+        let (best_move, value) = Solver::new().best_move(&state, &rules, 4, None);
+        assert_eq!(best_move, 1);
+        assert!(value > 5.0, "expected a decisive advantage, got {value}");
+    }
+}